@@ -91,7 +91,7 @@ use std::io::{Read, Write};
 #[derive(Debug)]
 pub struct Rofi<'a, T>
 where
-    T: AsRef<str>
+    T: RofiEntry
 {
     elements: &'a Vec<T>,
     case_sensitive: bool,
@@ -139,6 +139,53 @@ impl RofiChild<String> {
             Err(Error::Interrupted{})
         }
     }
+
+    /// Wait for the result and pair the selected string with the action that
+    /// triggered the exit. Rofi communicates the outcome through its exit code:
+    /// `0` accepts a row, `1` is a cancel/escape, and `10..=28` map to the
+    /// custom keybindings `-kb-custom-1` ..= `-kb-custom-19` (exit code `9 + N`).
+    fn wait_with_event(&mut self) -> Result<Event, Error> {
+        let status = self.p.wait()?;
+        let action = match status.code() {
+            Some(0) => Action::Accept,
+            Some(1) => Action::Cancel,
+            Some(code) if (10..=28).contains(&code) => Action::CustomKey((code - 9) as usize),
+            _ => return Err(Error::Interrupted{}),
+        };
+        let mut buffer = String::new();
+        if let Some(mut reader) = self.p.stdout.take() {
+            reader.read_to_string(&mut buffer)?;
+        }
+        if buffer.ends_with('\n') {
+            buffer.pop();
+        }
+        Ok(Event{selection: buffer, action})
+    }
+}
+
+impl RofiChild<String> {
+    /// Wait for the result and return every selected row as a separate String.
+    /// With `-multi-select` rofi prints one entry per line, so the buffer is
+    /// split on `\n` with the trailing empty line dropped.
+    fn wait_with_output_multi(&mut self) -> Result<Vec<String>, Error> {
+        let status = self.p.wait()?;
+        if status.success() {
+            let mut buffer = String::new();
+            if let Some(mut reader) = self.p.stdout.take() {
+                reader.read_to_string(&mut buffer)?;
+            }
+            if buffer.ends_with('\n') {
+                buffer.pop();
+            }
+            if buffer.len() == 0 {
+                Err(Error::Blank{})
+            } else {
+                Ok(buffer.split('\n').map(|l| l.to_string()).collect())
+            }
+        } else {
+            Err(Error::Interrupted{})
+        }
+    }
 }
 
 impl RofiChild<usize> {
@@ -167,11 +214,42 @@ impl RofiChild<usize> {
             Err(Error::Interrupted{})
         }
     }
+
+    /// Wait for the result and return the index of every selected row. Each
+    /// line is parsed and bounds-checked against `num_elements` exactly as the
+    /// single `usize` path does.
+    fn wait_with_output_multi(&mut self) -> Result<Vec<usize>, Error> {
+        let status = self.p.wait()?;
+        if status.success() {
+            let mut buffer = String::new();
+            if let Some(mut reader) = self.p.stdout.take() {
+                reader.read_to_string(&mut buffer)?;
+            }
+            if buffer.ends_with('\n') {
+                buffer.pop();
+            }
+            if buffer.len() == 0 {
+                Err(Error::Blank{})
+            } else {
+                let mut indices = Vec::new();
+                for line in buffer.split('\n') {
+                    let idx: isize = line.parse::<isize>()?;
+                    if idx < 0 || idx > self.num_elements as isize {
+                        return Err(Error::NotFound{});
+                    }
+                    indices.push(idx as usize);
+                }
+                Ok(indices)
+            }
+        } else {
+            Err(Error::Interrupted{})
+        }
+    }
 }
 
 impl<'a, T> Rofi<'a, T>
 where
-    T: AsRef<str>
+    T: RofiEntry
 {
     /// Generate a new, unconfigured Rofi window based on the elements provided.
     pub fn new(elements: &'a Vec<T>) -> Self {
@@ -197,6 +275,43 @@ where
         self.spawn_index()?.wait_with_output()
     }
 
+    /// Show the window, and return the selected string together with the
+    /// [`Action`] that triggered the exit. Use this to build multi-action menus
+    /// where a custom keybinding (see `self.custom_key`) performs a different
+    /// operation on the highlighted row than a plain accept.
+    pub fn run_with_event(&self) -> Result<Event, Error> {
+        self.spawn()?.wait_with_event()
+    }
+
+    /// Bind `-kb-custom-N <binding>` so that pressing `binding` exits rofi with
+    /// the custom exit code for slot `n` (1 ..= 19). The triggering key is
+    /// reported as `Action::CustomKey(n)` by `self.run_with_event`.
+    pub fn custom_key(&mut self, n: usize, binding: impl Into<String>) -> &mut Self {
+        self.args.push(format!("-kb-custom-{}", n));
+        self.args.push(binding.into());
+        self
+    }
+
+    /// Show the window in multi-select mode and return every chosen row as a
+    /// String. Requires `self.multi_select` to have been called.
+    pub fn run_multi(&self) -> Result<Vec<String>, Error> {
+        self.spawn()?.wait_with_output_multi()
+    }
+
+    /// Show the window in multi-select mode and return the index of every
+    /// chosen row. Requires `self.multi_select` to have been called. This
+    /// function will overwrite any subsequent calls to `self.format`.
+    pub fn run_multi_index(&mut self) -> Result<Vec<usize>, Error> {
+        self.spawn_index()?.wait_with_output_multi()
+    }
+
+    /// enable multi-select mode, allowing several rows to be returned from a
+    /// single prompt
+    pub fn multi_select(&mut self) -> &mut Self {
+        self.args.push("-multi-select".to_string());
+        self
+    }
+
     /// enable pango markup
     pub fn pango(&mut self) -> &mut Self {
         self.args.push("-markup-rows".to_string());
@@ -230,6 +345,23 @@ where
         self
     }
 
+    /// Set the matching algorithm used to filter the list (`-matching`). The
+    /// default rofi behaviour is `Matching::Normal` (substring matching).
+    pub fn matching(&mut self, matching: Matching) -> &mut Self {
+        self.args.push("-matching".to_string());
+        self.args.push(matching.as_arg().to_string());
+        self
+    }
+
+    /// Sort the filtered list by match score. Emits `-sorting-method fzf`
+    /// whatever the matching mode; this is mostly useful together with
+    /// `Matching::Fuzzy`.
+    pub fn sort_fuzzy(&mut self) -> &mut Self {
+        self.args.push("-sorting-method".to_string());
+        self.args.push("fzf".to_string());
+        self
+    }
+
     /// Set the prompt of the rofi window
     pub fn prompt(&mut self, prompt: impl Into<String>) -> &mut Self {
         self.args.push("-p".to_string());
@@ -237,6 +369,23 @@ where
         self
     }
 
+    /// Set an informational banner rendered above the list (`-mesg`). The
+    /// string accepts pango markup, so a [`pango::Pango`]-built string can be
+    /// passed directly.
+    pub fn message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.args.push("-mesg".to_string());
+        self.args.push(message.into());
+        self
+    }
+
+    /// Show an ephemeral error string (`-e`), e.g. to give feedback after a
+    /// rejected entry. Also accepts pango markup.
+    pub fn error_message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.args.push("-e".to_string());
+        self.args.push(message.into());
+        self
+    }
+
     /// Set the rofi theme
     /// This will make sure that rofi uses `~/.config/rofi/{theme}.rasi`
     pub fn theme(&mut self, theme: Option<impl Into<String>>) -> &mut Self {
@@ -270,6 +419,12 @@ where
     }
 
     fn spawn_child(&self) -> Result<Child, std::io::Error> {
+        // If any element carries row metadata we switch to rofi's extended
+        // dmenu protocol and turn on icon rendering.
+        let extended = self.elements.iter().any(|e| {
+            e.icon().is_some() || e.meta().is_some() || e.info().is_some() || e.nonselectable()
+        });
+
         let mut child = Command::new("rofi")
             .arg("-dmenu")
             .args(&self.args)
@@ -290,6 +445,9 @@ where
                 Width::Pixels(x) => vec!["-width".to_string(), format!("{}", x)],
                 Width::Characters(x) => vec!["-width".to_string(), format!("-{}", x)],
             })
+            .args(if extended { vec!["-show-icons".to_string()] } else { vec![] })
+            .args(self.index_range_arg("-a", |e| e.active()))
+            .args(self.index_range_arg("-u", |e| e.urgent()))
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -297,13 +455,177 @@ where
 
         if let Some(mut writer) = child.stdin.take() {
             for element in self.elements {
-                writer.write_all(element.as_ref().as_bytes())?;
+                if extended {
+                    writer.write_all(Self::extended_row(element).as_bytes())?;
+                } else {
+                    writer.write_all(element.display().as_bytes())?;
+                }
                 writer.write(b"\n")?;
             }
         }
         Ok(child)
     }
 
+    /// Build the `-a`/`-u` argument pair for the rows matching `pred`, e.g.
+    /// `["-a", "0,2,3"]`. Returns an empty vector when no row matches.
+    fn index_range_arg(&self, flag: &str, pred: impl Fn(&T) -> bool) -> Vec<String> {
+        let indices: Vec<String> = self.elements.iter().enumerate()
+            .filter(|(_, e)| pred(e))
+            .map(|(i, _)| i.to_string())
+            .collect();
+        if indices.is_empty() {
+            vec![]
+        } else {
+            vec![flag.to_string(), indices.join(",")]
+        }
+    }
+
+    /// Serialise a single row in rofi's extended dmenu form: the display
+    /// string, a NUL byte, then the metadata fields joined with the 0x1f unit
+    /// separator.
+    fn extended_row(element: &T) -> String {
+        let mut row = element.display().to_string();
+        row.push('\0');
+        let mut fields: Vec<String> = Vec::new();
+        if let Some(icon) = element.icon() {
+            fields.push(format!("icon\x1f{}", icon));
+        }
+        if let Some(meta) = element.meta() {
+            fields.push(format!("meta\x1f{}", meta));
+        }
+        if let Some(info) = element.info() {
+            fields.push(format!("info\x1f{}", info));
+        }
+        if element.nonselectable() {
+            fields.push("nonselectable\x1ftrue".to_string());
+        }
+        row.push_str(&fields.join("\x1f"));
+        row
+    }
+
+}
+
+/// A row that can be fed to rofi.
+///
+/// Any `AsRef<str>` type is a plain text row via a blanket implementation, so
+/// the existing `Vec<String>` / `Vec<&str>` inputs keep working unchanged. For
+/// icons, extra search keywords, hidden payloads or dimmed/highlighted rows,
+/// build an [`Entry`] instead.
+pub trait RofiEntry {
+    /// The displayed text of the row (may contain pango markup).
+    fn display(&self) -> &str;
+    /// An icon name or path, rendered when icons are enabled.
+    fn icon(&self) -> Option<&str> { None }
+    /// Extra keywords the row is matched against but which are not displayed.
+    fn meta(&self) -> Option<&str> { None }
+    /// A hidden payload passed to rofi with the row but not displayed. The
+    /// `run*` methods return the row's text or index, not this payload.
+    fn info(&self) -> Option<&str> { None }
+    /// Whether the row can be highlighted but not accepted.
+    fn nonselectable(&self) -> bool { false }
+    /// Whether the row is marked urgent (via the `-u` index range).
+    fn urgent(&self) -> bool { false }
+    /// Whether the row is marked active (via the `-a` index range).
+    fn active(&self) -> bool { false }
+}
+
+impl<T: AsRef<str>> RofiEntry for T {
+    fn display(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// A rofi row carrying optional dmenu row metadata.
+///
+/// Construct one with `Entry::new` and layer on metadata with the builder
+/// methods:
+///
+/// ```
+/// use rofi::Entry;
+///
+/// let entries = vec![
+///     Entry::new("Open").icon("document-open").info("open"),
+///     Entry::new("Delete").icon("edit-delete").urgent(),
+/// ];
+/// # let _ = rofi::Rofi::new(&entries);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Entry {
+    display: String,
+    icon: Option<String>,
+    meta: Option<String>,
+    info: Option<String>,
+    nonselectable: bool,
+    urgent: bool,
+    active: bool,
+}
+
+impl Entry {
+    /// Create a new entry displaying `display`.
+    pub fn new(display: impl Into<String>) -> Self {
+        Self { display: display.into(), ..Default::default() }
+    }
+
+    /// Set the icon name or path.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set extra keywords the row is matched against.
+    pub fn meta(mut self, meta: impl Into<String>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    /// Set a hidden payload passed to rofi with the row (not displayed, and
+    /// not part of what the `run*` methods return).
+    pub fn info(mut self, info: impl Into<String>) -> Self {
+        self.info = Some(info.into());
+        self
+    }
+
+    /// Mark the row as highlightable but not selectable.
+    pub fn nonselectable(mut self) -> Self {
+        self.nonselectable = true;
+        self
+    }
+
+    /// Mark the row as urgent.
+    pub fn urgent(mut self) -> Self {
+        self.urgent = true;
+        self
+    }
+
+    /// Mark the row as active.
+    pub fn active(mut self) -> Self {
+        self.active = true;
+        self
+    }
+}
+
+impl RofiEntry for Entry {
+    fn display(&self) -> &str {
+        &self.display
+    }
+    fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+    fn meta(&self) -> Option<&str> {
+        self.meta.as_deref()
+    }
+    fn info(&self) -> Option<&str> {
+        self.info.as_deref()
+    }
+    fn nonselectable(&self) -> bool {
+        self.nonselectable
+    }
+    fn urgent(&self) -> bool {
+        self.urgent
+    }
+    fn active(&self) -> bool {
+        self.active
+    }
 }
 
 /// Width of the rofi window to overwrite the default width from the rogi theme.
@@ -358,6 +680,55 @@ impl Format {
     }
 }
 
+/// The action that caused rofi to exit, as derived from its exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// The user accepted a row (exit code 0).
+    Accept,
+    /// The user pressed the custom keybinding for slot `n` (exit code `9 + n`).
+    CustomKey(usize),
+    /// The user cancelled or escaped the window (exit code 1).
+    Cancel,
+}
+
+/// The outcome of `Rofi::run_with_event`: the selected string paired with the
+/// [`Action`] that triggered it. On a [`Action::Cancel`] the selection is the
+/// empty string.
+#[derive(Debug)]
+pub struct Event {
+    /// The selected string, as rofi printed it to stdout.
+    pub selection: String,
+    /// The action that caused rofi to exit.
+    pub action: Action,
+}
+
+/// The matching algorithm rofi uses to filter the list (`-matching`).
+#[derive(Debug)]
+pub enum Matching {
+    /// Substring matching (rofi's default).
+    Normal,
+    /// Treat the input as a regular expression.
+    Regex,
+    /// Treat the input as a glob pattern.
+    Glob,
+    /// Fuzzy matching.
+    Fuzzy,
+    /// Prefix matching.
+    Prefix,
+}
+
+impl Matching {
+    fn as_arg(&self) -> &'static str {
+        match self {
+            Matching::Normal => "normal",
+            Matching::Regex => "regex",
+            Matching::Glob => "glob",
+            Matching::Fuzzy => "fuzzy",
+            Matching::Prefix => "prefix",
+        }
+    }
+}
+
 /// Rofi Error Type
 #[derive(Error, Debug)]
 pub enum Error {